@@ -48,10 +48,10 @@ fn show_repo_info(path: &PathBuf) -> Result<(), git2::Error> {
     }
 
     // Get remote
-    if let Ok(remote) = repo.find_remote("origin") {
-        if let Some(url) = remote.url() {
-            println!("Remote URL: {}", url);
-        }
+    if let Ok(remote) = repo.find_remote("origin")
+        && let Some(url) = remote.url()
+    {
+        println!("Remote URL: {}", url);
     }
 
     // Check if repository is bare